@@ -4,7 +4,8 @@
 #![allow(clippy::multiple_crate_versions)] // Should update as soon as possible
 
 use std::os::raw::{c_char, c_int};
-use std::{ptr, slice};
+use std::time::Duration;
+use std::{ptr, slice, thread};
 
 use ::windows_capture::dxgi_duplication_api::{DxgiDuplicationApi, Error as DxgiDuplicationError};
 use ::windows_capture::monitor::Monitor;
@@ -21,31 +22,289 @@ use windows::Win32::Graphics::Dxgi::Common::{
     DXGI_FORMAT, DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_FORMAT_R8G8B8A8_UNORM, DXGI_FORMAT_R16G16B16A16_FLOAT,
     DXGI_SAMPLE_DESC,
 };
+use windows::Win32::Graphics::Dxgi::{
+    DXGI_ERROR_MODE_CHANGE_IN_PROGRESS, DXGI_ERROR_NOT_CURRENTLY_AVAILABLE, DXGI_ERROR_SESSION_DISCONNECTED,
+    DXGI_OUTDUPL_POINTER_SHAPE_TYPE_COLOR, DXGI_OUTDUPL_POINTER_SHAPE_TYPE_MASKED_COLOR,
+    DXGI_OUTDUPL_POINTER_SHAPE_TYPE_MONOCHROME,
+};
+use windows::Win32::Foundation::RECT;
+use windows::Win32::System::Performance::QueryPerformanceFrequency;
+use pyo3::types::PyDict;
 
 /// Fastest Windows Screen Capture Library For Python 🔥.
 #[pymodule]
 fn windows_capture(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<NativeDxgiDuplication>()?;
     m.add_class::<NativeDxgiDuplicationFrame>()?;
+    m.add_class::<NativeDxgiDuplicationDesktop>()?;
+    m.add_class::<NativeDxgiDuplicationDesktopFrame>()?;
     Ok(())
 }
 
+/// Shape of the hardware cursor as last reported by `GetFramePointerShape`.
+///
+/// The shape bitmap is only delivered on the frame where it changes, so this is cached on the
+/// session and reused for as long as the pointer stays visible without a shape update.
+#[derive(Clone)]
+struct CursorShape {
+    shape_type: &'static str,
+    width: u32,
+    height: u32,
+    pitch: u32,
+    hot_spot_x: i32,
+    hot_spot_y: i32,
+    mask: Vec<u8>,
+}
+
+fn cursor_shape_type_to_str(shape_type: u32) -> &'static str {
+    match shape_type {
+        t if t == DXGI_OUTDUPL_POINTER_SHAPE_TYPE_MONOCHROME.0 as u32 => "monochrome",
+        t if t == DXGI_OUTDUPL_POINTER_SHAPE_TYPE_COLOR.0 as u32 => "color",
+        t if t == DXGI_OUTDUPL_POINTER_SHAPE_TYPE_MASKED_COLOR.0 as u32 => "masked_color",
+        _ => "unknown",
+    }
+}
+
+/// A rectangle in the coordinate space of the returned frame, as `(x, y, width, height)`.
+///
+/// For move rects, `x`/`y` are the *source* point the region was copied from rather than the
+/// destination, matching `DXGI_OUTDUPL_MOVE_RECT::SourcePoint`.
+type FrameRect = (i32, i32, u32, u32);
+
+fn clip_rect_to_box(left: i32, top: i32, right: i32, bottom: i32, src_box: &D3D11_BOX) -> Option<(i32, i32, u32, u32)> {
+    let clipped_left = left.max(src_box.left as i32);
+    let clipped_top = top.max(src_box.top as i32);
+    let clipped_right = right.min(src_box.right as i32);
+    let clipped_bottom = bottom.min(src_box.bottom as i32);
+
+    if clipped_right <= clipped_left || clipped_bottom <= clipped_top {
+        return None;
+    }
+
+    Some((
+        clipped_left - src_box.left as i32,
+        clipped_top - src_box.top as i32,
+        (clipped_right - clipped_left) as u32,
+        (clipped_bottom - clipped_top) as u32,
+    ))
+}
+
+/// How a captured frame's pixels should be packed for the Python caller.
+///
+/// `Native` passes through whatever the desktop's DXGI format is, row-pitch padding included
+/// (zero-copy). Every other variant is tightly packed (no stride padding) and requires the CPU to
+/// repack each row while reading it off the mapped staging texture.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Native,
+    Rgb24,
+    Bgr24,
+    /// `DXGI_FORMAT_R10G10B10A2_UNORM` layout: R, G, B packed 10 bits each, alpha 2 bits, LE u32.
+    Rgb10A2,
+}
+
+impl OutputFormat {
+    fn parse(name: &str) -> PyResult<Self> {
+        match name {
+            "native" => Ok(Self::Native),
+            "rgb24" => Ok(Self::Rgb24),
+            "bgr24" => Ok(Self::Bgr24),
+            "rgb10a2" => Ok(Self::Rgb10A2),
+            other => Err(PyException::new_err(format!(
+                "Unsupported output_format {other:?}; expected one of \"native\", \"rgb24\", \"bgr24\", \"rgb10a2\""
+            ))),
+        }
+    }
+
+    const fn bytes_per_pixel(self) -> usize {
+        match self {
+            Self::Native => 0,
+            Self::Rgb24 | Self::Bgr24 => 3,
+            Self::Rgb10A2 => 4,
+        }
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Native => "native",
+            Self::Rgb24 => "rgb24",
+            Self::Bgr24 => "bgr24",
+            Self::Rgb10A2 => "rgb10a2",
+        }
+    }
+}
+
+/// Decodes an IEEE 754 binary16 value to `f32`.
+fn half_to_f32(half: u16) -> f32 {
+    let sign = u32::from(half >> 15) << 31;
+    let exponent = u32::from((half >> 10) & 0x1F);
+    let mantissa = u32::from(half & 0x3FF);
+
+    let bits = if exponent == 0 {
+        if mantissa == 0 {
+            sign
+        } else {
+            let mut exponent: i32 = 0;
+            let mut mantissa = mantissa;
+            while mantissa & 0x400 == 0 {
+                mantissa <<= 1;
+                exponent -= 1;
+            }
+            mantissa &= 0x3FF;
+            sign | ((exponent + 113) as u32) << 23 | (mantissa << 13)
+        }
+    } else if exponent == 0x1F {
+        sign | (0xFF << 23) | (mantissa << 13)
+    } else {
+        sign | ((exponent + 112) << 23) | (mantissa << 13)
+    };
+
+    f32::from_bits(bits)
+}
+
+/// Reinhard tone map from a scene-referred linear HDR channel down to the `0..=1` SDR range.
+fn tone_map(value: f32, white_point: f32) -> f32 {
+    let value = (value / white_point).max(0.0);
+    value / (1.0 + value)
+}
+
+/// Reads one pixel from the mapped staging row at `src` as `[r, g, b, a]` in `0.0..=1.0`,
+/// applying the HDR tone map when the source is `Rgba16F`.
+unsafe fn read_source_channels(src: *const u8, color_format: ColorFormat, hdr_white_point: f32) -> [f32; 4] {
+    match color_format {
+        ColorFormat::Bgra8 => unsafe {
+            [
+                f32::from(*src.add(2)) / 255.0,
+                f32::from(*src.add(1)) / 255.0,
+                f32::from(*src) / 255.0,
+                f32::from(*src.add(3)) / 255.0,
+            ]
+        },
+        ColorFormat::Rgba8 => unsafe {
+            [
+                f32::from(*src) / 255.0,
+                f32::from(*src.add(1)) / 255.0,
+                f32::from(*src.add(2)) / 255.0,
+                f32::from(*src.add(3)) / 255.0,
+            ]
+        },
+        ColorFormat::Rgba16F => unsafe {
+            let src = src.cast::<u16>();
+            [
+                tone_map(half_to_f32(ptr::read_unaligned(src)), hdr_white_point),
+                tone_map(half_to_f32(ptr::read_unaligned(src.add(1))), hdr_white_point),
+                tone_map(half_to_f32(ptr::read_unaligned(src.add(2))), hdr_white_point),
+                half_to_f32(ptr::read_unaligned(src.add(3))).clamp(0.0, 1.0),
+            ]
+        },
+    }
+}
+
+/// Packs one pixel's `[r, g, b, a]` channels (each `0.0..=1.0`) into `dst` per `output_format`.
+unsafe fn pack_pixel(channels: [f32; 4], output_format: OutputFormat, dst: *mut u8) {
+    match output_format {
+        OutputFormat::Native => unreachable!("native output is never repacked"),
+        OutputFormat::Rgb24 => unsafe {
+            ptr::write(dst, (channels[0] * 255.0).round() as u8);
+            ptr::write(dst.add(1), (channels[1] * 255.0).round() as u8);
+            ptr::write(dst.add(2), (channels[2] * 255.0).round() as u8);
+        },
+        OutputFormat::Bgr24 => unsafe {
+            ptr::write(dst, (channels[2] * 255.0).round() as u8);
+            ptr::write(dst.add(1), (channels[1] * 255.0).round() as u8);
+            ptr::write(dst.add(2), (channels[0] * 255.0).round() as u8);
+        },
+        OutputFormat::Rgb10A2 => unsafe {
+            let r = ((channels[0] * 1023.0).round() as u32).min(1023);
+            let g = ((channels[1] * 1023.0).round() as u32).min(1023);
+            let b = ((channels[2] * 1023.0).round() as u32).min(1023);
+            let a = ((channels[3] * 3.0).round() as u32).min(3);
+            let packed = r | (g << 10) | (b << 20) | (a << 30);
+            ptr::write_unaligned(dst.cast::<u32>(), packed);
+        },
+    }
+}
+
+/// Default number of `IDXGIOutputDuplication` (re)creation attempts before giving up.
+const DEFAULT_RECREATE_ATTEMPTS: u32 = 10;
+/// Default delay between creation attempts, chosen to ride out a display-mode transition without
+/// noticeably delaying startup in the common case.
+const DEFAULT_RECREATE_DELAY_MS: u64 = 50;
+
+/// Whether a duplication-creation failure is likely transient (display mode/resolution/DPI is
+/// mid-change, or the previous session just dropped with `AccessLost`) as opposed to fatal
+/// (adapter removed, access denied).
+///
+/// Classified by HRESULT rather than by matching the error's `Display` text, which is free to
+/// change (or be localized) without notice.
+fn is_retryable_duplication_error(error: &DxgiDuplicationError) -> bool {
+    if matches!(error, DxgiDuplicationError::AccessLost) {
+        return true;
+    }
+
+    let Some(hresult) = std::error::Error::source(error)
+        .and_then(|source| source.downcast_ref::<windows::core::Error>())
+        .map(windows::core::Error::code)
+    else {
+        return false;
+    };
+
+    matches!(
+        hresult,
+        DXGI_ERROR_NOT_CURRENTLY_AVAILABLE | DXGI_ERROR_SESSION_DISCONNECTED | DXGI_ERROR_MODE_CHANGE_IN_PROGRESS
+    )
+}
+
+/// Converts a QPC tick count (as reported in `DXGI_OUTDUPL_FRAME_INFO`) to seconds using the
+/// process-wide performance counter frequency. Returns `0.0` if the platform reports a zero
+/// frequency, which should not happen on any supported version of Windows.
+fn qpc_ticks_to_seconds(ticks: i64) -> f64 {
+    let mut frequency = 0_i64;
+    let frequency = if unsafe { QueryPerformanceFrequency(&mut frequency) }.as_bool() {
+        frequency
+    } else {
+        0
+    };
+    if frequency == 0 { 0.0 } else { ticks as f64 / frequency as f64 }
+}
+
 #[pyclass(unsendable)]
 pub struct NativeDxgiDuplication {
     duplication: DxgiDuplicationApi,
     monitor: Monitor,
+    last_cursor_shape: Option<CursorShape>,
+    /// Staging texture reused across calls in `copy_dirty_only` mode, keyed by its dimensions,
+    /// format and crop box so a resolution or crop change forces a fresh full copy.
+    cached_staging: Option<(ID3D11Texture2D, D3D11_TEXTURE2D_DESC, D3D11_BOX)>,
+    recreate_attempts: u32,
+    recreate_delay_ms: u64,
 }
 
 impl NativeDxgiDuplication {
-    fn new_duplication(monitor: Monitor) -> Result<(Monitor, DxgiDuplicationApi), DxgiDuplicationError> {
-        let duplication = DxgiDuplicationApi::new(monitor)?;
+    fn new_duplication(
+        monitor: Monitor,
+        attempts: u32,
+        delay_ms: u64,
+    ) -> Result<(Monitor, DxgiDuplicationApi), DxgiDuplicationError> {
+        let attempts = attempts.max(1);
+
+        for attempt in 0..attempts {
+            match DxgiDuplicationApi::new(monitor) {
+                Ok(duplication) => return Ok((monitor, duplication)),
+                Err(e) if attempt + 1 < attempts && is_retryable_duplication_error(&e) => {
+                    thread::sleep(Duration::from_millis(delay_ms));
+                }
+                Err(e) => return Err(e),
+            }
+        }
 
-        Ok((monitor, duplication))
+        unreachable!("loop above always returns before exhausting its range")
     }
 
     fn recreate_duplication(&mut self) -> Result<(), DxgiDuplicationError> {
-        let (_, duplication) = Self::new_duplication(self.monitor)?;
+        let (_, duplication) = Self::new_duplication(self.monitor, self.recreate_attempts, self.recreate_delay_ms)?;
         self.duplication = duplication;
+        self.cached_staging = None;
         Ok(())
     }
 
@@ -77,8 +336,8 @@ impl NativeDxgiDuplication {
 #[pymethods]
 impl NativeDxgiDuplication {
     #[new]
-    #[pyo3(signature = (monitor_index=None))]
-    pub fn new(monitor_index: Option<usize>) -> PyResult<Self> {
+    #[pyo3(signature = (monitor_index=None, recreate_attempts=DEFAULT_RECREATE_ATTEMPTS, recreate_delay_ms=DEFAULT_RECREATE_DELAY_MS))]
+    pub fn new(monitor_index: Option<usize>, recreate_attempts: u32, recreate_delay_ms: u64) -> PyResult<Self> {
         let monitor = match monitor_index {
             Some(index) => Monitor::from_index(index)
                 .map_err(|e| PyException::new_err(format!("Failed to resolve monitor from index {index}: {e}",)))?,
@@ -86,110 +345,315 @@ impl NativeDxgiDuplication {
                 .map_err(|e| PyException::new_err(format!("Failed to acquire primary monitor: {e}",)))?,
         };
 
-        let (_, duplication) = Self::new_duplication(monitor)
+        let (_, duplication) = Self::new_duplication(monitor, recreate_attempts, recreate_delay_ms)
             .map_err(|e| PyException::new_err(format!("Failed to create DXGI duplication session: {e}")))?;
 
-        Ok(Self { duplication, monitor })
+        Ok(Self {
+            duplication,
+            monitor,
+            last_cursor_shape: None,
+            cached_staging: None,
+            recreate_attempts,
+            recreate_delay_ms,
+        })
     }
 
-    #[pyo3(signature = (timeout_ms=16, area=None))]
+    #[pyo3(
+        signature = (timeout_ms=16, area=None, draw_cursor=false, copy_dirty_only=false, auto_recreate=false, output_format=None, hdr_white_point=1.0)
+    )]
+    #[allow(clippy::too_many_arguments)]
     pub fn acquire_next_frame(
         &mut self,
         timeout_ms: u32,
         area: Option<Vec<i32>>,
+        draw_cursor: bool,
+        copy_dirty_only: bool,
+        auto_recreate: bool,
+        output_format: Option<String>,
+        hdr_white_point: f32,
     ) -> PyResult<Option<NativeDxgiDuplicationFrame>> {
-        match self.duplication.acquire_next_frame(timeout_ms) {
-            Ok(frame) => {
-                let texture_desc = *frame.texture_desc();
-                let color_format = Self::color_format_from_dxgi(texture_desc.Format)?;
-                let bytes_per_pixel = Self::bytes_per_pixel(color_format);
-                let mut src_box = D3D11_BOX {
-                    left: 0,
-                    top: 0,
-                    front: 0,
-                    right: texture_desc.Width,
-                    bottom: texture_desc.Height,
-                    back: 1,
-                };
-
-                if let Some(xywh) = area {
-                    if xywh.iter().all(|&x| x >= 0) {
-                        src_box.left = xywh[0] as u32;
-                        src_box.top = xywh[1] as u32;
-                        src_box.right = xywh[2] as u32;
-                        src_box.bottom = xywh[3] as u32;
+        let output_format = match &output_format {
+            Some(name) => OutputFormat::parse(name)?,
+            None => OutputFormat::Native,
+        };
+
+        // The native path hands the staging texture out still-mapped, so it can never be cached for
+        // reuse (see the `cached_staging` assignment below) — reject the combination up front instead
+        // of silently degrading `copy_dirty_only` to a full copy every frame.
+        if copy_dirty_only && output_format == OutputFormat::Native {
+            return Err(PyException::new_err(
+                "copy_dirty_only requires a non-native output_format (the native zero-copy path cannot cache a still-mapped staging texture)",
+            ));
+        }
+
+        let mut recreated_after_access_lost = false;
+
+        loop {
+            match self.duplication.acquire_next_frame(timeout_ms) {
+                Ok(frame) => {
+                    let frame_info = *frame.frame_info();
+                    let texture_desc = *frame.texture_desc();
+                    let color_format = Self::color_format_from_dxgi(texture_desc.Format)?;
+                    let bytes_per_pixel = Self::bytes_per_pixel(color_format);
+                    let mut src_box = D3D11_BOX {
+                        left: 0,
+                        top: 0,
+                        front: 0,
+                        right: texture_desc.Width,
+                        bottom: texture_desc.Height,
+                        back: 1,
+                    };
+
+                    if let Some(xywh) = &area {
+                        if xywh.iter().all(|&x| x >= 0) {
+                            src_box.left = xywh[0] as u32;
+                            src_box.top = xywh[1] as u32;
+                            src_box.right = xywh[2] as u32;
+                            src_box.bottom = xywh[3] as u32;
+                        }
                     }
-                }
 
-                let width = src_box.right - src_box.left;
-                let height = src_box.bottom - src_box.top;
-
-                let staging_desc = D3D11_TEXTURE2D_DESC {
-                    Width: width,
-                    Height: height,
-                    MipLevels: 1,
-                    ArraySize: 1,
-                    Format: texture_desc.Format,
-                    SampleDesc: DXGI_SAMPLE_DESC { Count: 1, Quality: 0 },
-                    Usage: D3D11_USAGE_STAGING,
-                    BindFlags: 0,
-                    CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
-                    MiscFlags: 0,
-                };
-
-                let device_context = frame.device_context().clone();
-                let device = frame.device().clone();
-
-                let mut staging = None;
-                unsafe { device.CreateTexture2D(&staging_desc, None, Some(&mut staging)) }
-                    .map_err(|e| PyException::new_err(format!("Failed to create staging texture: {e}")))?;
-                let staging = staging.expect("CreateTexture2D returned Ok but no texture");
-
-                unsafe {
-                    device_context.CopySubresourceRegion(
-                        &staging,
-                        0,
-                        0,
-                        0,
-                        0,
-                        frame.texture(),
-                        0,
-                        Some(&src_box as *const _),
+                    let width = src_box.right - src_box.left;
+                    let height = src_box.bottom - src_box.top;
+
+                    let staging_desc = D3D11_TEXTURE2D_DESC {
+                        Width: width,
+                        Height: height,
+                        MipLevels: 1,
+                        ArraySize: 1,
+                        Format: texture_desc.Format,
+                        SampleDesc: DXGI_SAMPLE_DESC { Count: 1, Quality: 0 },
+                        Usage: D3D11_USAGE_STAGING,
+                        BindFlags: 0,
+                        CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+                        MiscFlags: 0,
+                    };
+
+                    let device_context = frame.device_context().clone();
+                    let device = frame.device().clone();
+
+                    let mut move_rects: Vec<FrameRect> = Vec::new();
+                    let mut dirty_rects: Vec<FrameRect> = Vec::new();
+                    let mut dirty_copy_boxes: Vec<D3D11_BOX> = Vec::new();
+
+                    if frame_info.TotalMetadataBufferSize > 0 {
+                        for mr in self
+                            .duplication
+                            .get_frame_move_rects(frame_info.TotalMetadataBufferSize)
+                            .map_err(|e| PyException::new_err(format!("Failed to read move rects: {e}")))?
+                        {
+                            let dst = mr.DestinationRect;
+                            if let Some((x, y, w, h)) =
+                                clip_rect_to_box(dst.left, dst.top, dst.right, dst.bottom, &src_box)
+                            {
+                                let dx = mr.SourcePoint.x - dst.left;
+                                let dy = mr.SourcePoint.y - dst.top;
+                                move_rects.push((x + dx, y + dy, w, h));
+                                // Move rects relocate pixels already present elsewhere in the frame; an
+                                // incremental copy keyed on the destination rect would read/write the
+                                // wrong region of the staging texture, so a move forces a full copy below
+                                // instead of being folded into `dirty_copy_boxes`.
+                            }
+                        }
+
+                        for rect in self
+                            .duplication
+                            .get_frame_dirty_rects(frame_info.TotalMetadataBufferSize)
+                            .map_err(|e| PyException::new_err(format!("Failed to read dirty rects: {e}")))?
+                        {
+                            if let Some((x, y, w, h)) =
+                                clip_rect_to_box(rect.left, rect.top, rect.right, rect.bottom, &src_box)
+                            {
+                                dirty_rects.push((x, y, w, h));
+                                dirty_copy_boxes.push(D3D11_BOX {
+                                    left: (x + src_box.left as i32) as u32,
+                                    top: (y + src_box.top as i32) as u32,
+                                    front: 0,
+                                    right: (x + src_box.left as i32) as u32 + w,
+                                    bottom: (y + src_box.top as i32) as u32 + h,
+                                    back: 1,
+                                });
+                            }
+                        }
+                    }
+
+                    let reuse_cached = copy_dirty_only
+                        && self.cached_staging.as_ref().is_some_and(|(_, desc, cached_box)| {
+                            desc.Width == staging_desc.Width
+                                && desc.Height == staging_desc.Height
+                                && desc.Format == staging_desc.Format
+                                && cached_box.left == src_box.left
+                                && cached_box.top == src_box.top
+                        });
+
+                    let staging = if reuse_cached {
+                        self.cached_staging.as_ref().expect("checked above").0.clone()
+                    } else {
+                        let mut staging = None;
+                        unsafe { device.CreateTexture2D(&staging_desc, None, Some(&mut staging)) }
+                            .map_err(|e| PyException::new_err(format!("Failed to create staging texture: {e}")))?;
+                        staging.expect("CreateTexture2D returned Ok but no texture")
+                    };
+
+                    // A blended cursor is baked into the staging texture's pixels below, so an
+                    // incremental copy (which only refreshes DXGI's reported dirty rects, not the
+                    // pointer's prior position) would leave a ghost trail of past cursor positions;
+                    // fall back to a full copy whenever the cursor is drawn.
+                    if copy_dirty_only && reuse_cached && !draw_cursor && move_rects.is_empty() && !dirty_copy_boxes.is_empty() {
+                        for copy_box in &dirty_copy_boxes {
+                            let dst_x = copy_box.left - src_box.left;
+                            let dst_y = copy_box.top - src_box.top;
+                            unsafe {
+                                device_context.CopySubresourceRegion(
+                                    &staging,
+                                    0,
+                                    dst_x,
+                                    dst_y,
+                                    0,
+                                    frame.texture(),
+                                    0,
+                                    Some(copy_box as *const _),
+                                );
+                            }
+                        }
+                    } else {
+                        unsafe {
+                            device_context.CopySubresourceRegion(
+                                &staging,
+                                0,
+                                0,
+                                0,
+                                0,
+                                frame.texture(),
+                                0,
+                                Some(&src_box as *const _),
+                            );
+                        }
+                    }
+
+                    // Safe to cache unconditionally: `copy_dirty_only` with a native `output_format` is
+                    // rejected above, so reaching here with `copy_dirty_only` set means this staging
+                    // texture will be `Unmap`-ed below rather than handed out still-mapped.
+                    if copy_dirty_only {
+                        self.cached_staging = Some((staging.clone(), staging_desc, src_box));
+                    }
+
+                    let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+                    unsafe { device_context.Map(&staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped)) }
+                        .map_err(|e| PyException::new_err(format!("Failed to map duplication frame: {e}")))?;
+
+                    let row_pitch_u32 = mapped.RowPitch;
+                    let row_pitch = usize::try_from(row_pitch_u32)
+                        .map_err(|_| PyException::new_err("Failed to convert row pitch to usize"))?;
+                    let height_usize =
+                        usize::try_from(height).map_err(|_| PyException::new_err("Failed to convert height to usize"))?;
+                    let len = row_pitch
+                        .checked_mul(height_usize)
+                        .ok_or_else(|| PyException::new_err("Mapped frame size overflowed usize"))?;
+
+                    if frame_info.PointerShapeBufferSize > 0 {
+                        match self.duplication.get_frame_pointer_shape(frame_info.PointerShapeBufferSize) {
+                            Ok((shape_info, mask)) => {
+                                self.last_cursor_shape = Some(CursorShape {
+                                    shape_type: cursor_shape_type_to_str(shape_info.Type),
+                                    width: shape_info.Width,
+                                    height: shape_info.Height,
+                                    pitch: shape_info.Pitch,
+                                    hot_spot_x: shape_info.HotSpot.x,
+                                    hot_spot_y: shape_info.HotSpot.y,
+                                    mask,
+                                });
+                            }
+                            Err(e) => return Err(PyException::new_err(format!("Failed to fetch cursor shape: {e}"))),
+                        }
+                    }
+
+                    let cursor_visible = frame_info.PointerPosition.Visible.as_bool();
+                    let cursor_x = frame_info.PointerPosition.Position.x;
+                    let cursor_y = frame_info.PointerPosition.Position.y;
+
+                    if draw_cursor && cursor_visible && matches!(color_format, ColorFormat::Bgra8) {
+                        if let Some(shape) = &self.last_cursor_shape {
+                            unsafe {
+                                blend_cursor_into_mapped_buffer(
+                                    mapped.pData.cast::<u8>(),
+                                    row_pitch,
+                                    &src_box,
+                                    shape,
+                                    cursor_x,
+                                    cursor_y,
+                                );
+                            }
+                        }
+                    }
+
+                    let (ptr, len, row_pitch, bytes_per_pixel, color_format_str, owned) =
+                        if output_format == OutputFormat::Native {
+                            (mapped.pData.cast::<u8>(), len, row_pitch, bytes_per_pixel, Self::color_format_to_str(color_format), None)
+                        } else {
+                            let out_bpp = output_format.bytes_per_pixel();
+                            let out_row_pitch = width as usize * out_bpp;
+                            let mut packed = vec![0u8; out_row_pitch * height_usize];
+
+                            for row in 0..height_usize {
+                                for col in 0..width as usize {
+                                    unsafe {
+                                        let src = mapped.pData.cast::<u8>().add(row * row_pitch + col * bytes_per_pixel);
+                                        let channels = read_source_channels(src, color_format, hdr_white_point);
+                                        let dst = packed.as_mut_ptr().add(row * out_row_pitch + col * out_bpp);
+                                        pack_pixel(channels, output_format, dst);
+                                    }
+                                }
+                            }
+
+                            unsafe {
+                                device_context.Unmap(&staging, 0);
+                            }
+
+                            let ptr = packed.as_mut_ptr();
+                            let len = packed.len();
+                            (ptr, len, out_row_pitch, out_bpp, output_format.name(), Some(packed))
+                        };
+
+                    let frame_obj = NativeDxgiDuplicationFrame::new(
+                        device_context,
+                        staging,
+                        ptr,
+                        len,
+                        width,
+                        height,
+                        bytes_per_pixel,
+                        row_pitch,
+                        color_format_str,
+                        cursor_x,
+                        cursor_y,
+                        cursor_visible,
+                        self.last_cursor_shape.clone(),
+                        move_rects,
+                        dirty_rects,
+                        frame_info.LastPresentTime,
+                        frame_info.LastMouseUpdateTime,
+                        frame_info.AccumulatedFrames,
+                        frame_info.ProtectedContentMaskedOut.as_bool(),
+                        owned,
                     );
-                }
 
-                let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
-                unsafe { device_context.Map(&staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped)) }
-                    .map_err(|e| PyException::new_err(format!("Failed to map duplication frame: {e}")))?;
-
-                let row_pitch_u32 = mapped.RowPitch;
-                let row_pitch = usize::try_from(row_pitch_u32)
-                    .map_err(|_| PyException::new_err("Failed to convert row pitch to usize"))?;
-                let height_usize =
-                    usize::try_from(height).map_err(|_| PyException::new_err("Failed to convert height to usize"))?;
-                let len = row_pitch
-                    .checked_mul(height_usize)
-                    .ok_or_else(|| PyException::new_err("Mapped frame size overflowed usize"))?;
-
-                let frame_obj = NativeDxgiDuplicationFrame::new(
-                    device_context,
-                    staging,
-                    mapped.pData.cast::<u8>(),
-                    len,
-                    width,
-                    height,
-                    bytes_per_pixel,
-                    row_pitch,
-                    Self::color_format_to_str(color_format),
-                );
-
-                Ok(Some(frame_obj))
-            }
-            Err(DxgiDuplicationError::Timeout) => Ok(None),
-            Err(DxgiDuplicationError::AccessLost) => {
-                Err(PyException::new_err("DXGI duplication access lost; call recreate() to re-establish the session"))
+                    return Ok(Some(frame_obj));
+                }
+                Err(DxgiDuplicationError::Timeout) => return Ok(None),
+                Err(DxgiDuplicationError::AccessLost) if auto_recreate && !recreated_after_access_lost => {
+                    recreated_after_access_lost = true;
+                    self.recreate_duplication().map_err(|e| {
+                        PyException::new_err(format!("Failed to auto-recreate DXGI duplication session after access loss: {e}"))
+                    })?;
+                }
+                Err(DxgiDuplicationError::AccessLost) => {
+                    return Err(PyException::new_err(
+                        "DXGI duplication access lost; call recreate() to re-establish the session",
+                    ));
+                }
+                Err(other) => return Err(PyException::new_err(format!("Failed to acquire duplication frame: {other}"))),
             }
-            Err(other) => Err(PyException::new_err(format!("Failed to acquire duplication frame: {other}"))),
         }
     }
 
@@ -198,22 +662,131 @@ impl NativeDxgiDuplication {
         let monitor = Monitor::from_index(monitor_index)
             .map_err(|e| PyException::new_err(format!("Failed to resolve monitor from index {monitor_index}: {e}")))?;
 
-        let (_, duplication) = Self::new_duplication(monitor)
+        let (_, duplication) = Self::new_duplication(monitor, self.recreate_attempts, self.recreate_delay_ms)
             .map_err(|e| PyException::new_err(format!("Failed to create DXGI duplication session: {e}")))?;
 
         self.monitor = monitor;
         self.duplication = duplication;
+        self.last_cursor_shape = None;
+        self.cached_staging = None;
 
         Ok(())
     }
 
-    pub fn recreate(&mut self) -> PyResult<()> {
+    #[pyo3(signature = (attempts=None, delay_ms=None))]
+    pub fn recreate(&mut self, attempts: Option<u32>, delay_ms: Option<u64>) -> PyResult<()> {
+        if let Some(attempts) = attempts {
+            self.recreate_attempts = attempts;
+        }
+        if let Some(delay_ms) = delay_ms {
+            self.recreate_delay_ms = delay_ms;
+        }
+
         self.recreate_duplication()
             .map_err(|e| PyException::new_err(format!("Failed to recreate DXGI duplication session: {e}")))?;
         Ok(())
     }
 }
 
+/// Alpha-blends a cached cursor shape into a mapped staging buffer, clipped to `src_box`.
+///
+/// Monochrome cursors pack a 1bpp AND mask on top of a 1bpp XOR mask (the buffer height is
+/// doubled); masked-color cursors use the shape's alpha byte as a per-pixel flag choosing between
+/// copying the shape's RGB (`0x00`) and XOR-ing it into the destination (`0xFF`) instead of a true
+/// alpha. Pixels are written in B, G, R order, so the destination must be BGRA8; the caller skips
+/// blending for every other color format.
+unsafe fn blend_cursor_into_mapped_buffer(
+    dst: *mut u8,
+    dst_row_pitch: usize,
+    src_box: &D3D11_BOX,
+    shape: &CursorShape,
+    cursor_x: i32,
+    cursor_y: i32,
+) {
+    let dst_width = (src_box.right - src_box.left) as i32;
+    let dst_height = (src_box.bottom - src_box.top) as i32;
+    let origin_x = cursor_x - src_box.left as i32;
+    let origin_y = cursor_y - src_box.top as i32;
+
+    let mono = shape.shape_type == "monochrome";
+    let shape_height = if mono { shape.height / 2 } else { shape.height };
+
+    for row in 0..shape_height {
+        let py = origin_y + row as i32;
+        if py < 0 || py >= dst_height {
+            continue;
+        }
+        for col in 0..shape.width {
+            let px = origin_x + col as i32;
+            if px < 0 || px >= dst_width {
+                continue;
+            }
+
+            let dst_pixel = unsafe { dst.add(py as usize * dst_row_pitch + px as usize * 4) };
+
+            if mono {
+                let byte_idx = (row * shape.pitch + col / 8) as usize;
+                let bit = 7 - (col % 8);
+                let and_bit = (shape.mask[byte_idx] >> bit) & 1;
+                let xor_row_offset = shape_height * shape.pitch;
+                let xor_bit = (shape.mask[xor_row_offset as usize + byte_idx] >> bit) & 1;
+                if and_bit == 0 {
+                    let value = if xor_bit == 1 { 0xFF } else { 0x00 };
+                    unsafe {
+                        ptr::write(dst_pixel, value);
+                        ptr::write(dst_pixel.add(1), value);
+                        ptr::write(dst_pixel.add(2), value);
+                    }
+                } else if xor_bit == 1 {
+                    unsafe {
+                        for i in 0..3 {
+                            let p = dst_pixel.add(i);
+                            ptr::write(p, !ptr::read(p));
+                        }
+                    }
+                }
+            } else {
+                let src_pixel_idx = (row * shape.pitch + col * 4) as usize;
+                let [b, g, r, a] = [
+                    shape.mask[src_pixel_idx],
+                    shape.mask[src_pixel_idx + 1],
+                    shape.mask[src_pixel_idx + 2],
+                    shape.mask[src_pixel_idx + 3],
+                ];
+
+                if shape.shape_type == "masked_color" {
+                    // Per DXGI, masked-color alpha is always 0x00 (copy the RGB opaquely) or 0xFF
+                    // (XOR the RGB with the destination) — never a true blend coefficient.
+                    if a == 0xFF {
+                        unsafe {
+                            for i in 0..3 {
+                                let p = dst_pixel.add(i);
+                                ptr::write(p, ptr::read(p) ^ [b, g, r][i]);
+                            }
+                        }
+                    } else {
+                        unsafe {
+                            ptr::write(dst_pixel, b);
+                            ptr::write(dst_pixel.add(1), g);
+                            ptr::write(dst_pixel.add(2), r);
+                        }
+                    }
+                } else {
+                    let alpha = u32::from(a);
+                    unsafe {
+                        for (i, channel) in [b, g, r].into_iter().enumerate() {
+                            let p = dst_pixel.add(i);
+                            let bg = u32::from(ptr::read(p));
+                            let blended = (u32::from(channel) * alpha + bg * (255 - alpha)) / 255;
+                            ptr::write(p, blended as u8);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
 #[pyclass(unsendable)]
 pub struct NativeDxgiDuplicationFrame {
     context: windows::Win32::Graphics::Direct3D11::ID3D11DeviceContext,
@@ -225,6 +798,21 @@ pub struct NativeDxgiDuplicationFrame {
     bytes_per_pixel: usize,
     row_pitch: usize,
     color_format: &'static str,
+    cursor_x: i32,
+    cursor_y: i32,
+    cursor_visible: bool,
+    cursor_shape: Option<CursorShape>,
+    move_rects: Vec<FrameRect>,
+    dirty_rects: Vec<FrameRect>,
+    last_present_time: i64,
+    last_mouse_update_time: i64,
+    accumulated_frames: u32,
+    protected_content: bool,
+    /// Repacked pixel data when `output_format` requested conversion; `None` means `ptr` points
+    /// into the still-mapped `staging` texture (the zero-copy native-format path). Never read
+    /// directly — it exists solely to keep the backing allocation alive for as long as `ptr` does.
+    #[allow(dead_code)]
+    owned: Option<Vec<u8>>,
     mapped: bool,
 }
 
@@ -241,8 +829,42 @@ impl NativeDxgiDuplicationFrame {
         bytes_per_pixel: usize,
         row_pitch: usize,
         color_format: &'static str,
+        cursor_x: i32,
+        cursor_y: i32,
+        cursor_visible: bool,
+        cursor_shape: Option<CursorShape>,
+        move_rects: Vec<FrameRect>,
+        dirty_rects: Vec<FrameRect>,
+        last_present_time: i64,
+        last_mouse_update_time: i64,
+        accumulated_frames: u32,
+        protected_content: bool,
+        owned: Option<Vec<u8>>,
     ) -> Self {
-        Self { context, staging, ptr, len, width, height, bytes_per_pixel, row_pitch, color_format, mapped: true }
+        let mapped = owned.is_none();
+        Self {
+            context,
+            staging,
+            ptr,
+            len,
+            width,
+            height,
+            bytes_per_pixel,
+            row_pitch,
+            color_format,
+            cursor_x,
+            cursor_y,
+            cursor_visible,
+            cursor_shape,
+            move_rects,
+            dirty_rects,
+            last_present_time,
+            last_mouse_update_time,
+            accumulated_frames,
+            protected_content,
+            owned,
+            mapped,
+        }
     }
 }
 
@@ -287,6 +909,92 @@ impl NativeDxgiDuplicationFrame {
         self.row_pitch
     }
 
+    #[getter]
+    pub fn cursor_x(&self) -> i32 {
+        self.cursor_x
+    }
+
+    #[getter]
+    pub fn cursor_y(&self) -> i32 {
+        self.cursor_y
+    }
+
+    #[getter]
+    pub fn cursor_visible(&self) -> bool {
+        self.cursor_visible
+    }
+
+    /// Returns the last known cursor shape as a dict, or `None` if no shape has been reported yet.
+    pub fn cursor_shape<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(shape) = &self.cursor_shape else {
+            return Ok(None);
+        };
+
+        let dict = PyDict::new(py);
+        dict.set_item("type", shape.shape_type)?;
+        dict.set_item("width", shape.width)?;
+        dict.set_item("height", shape.height)?;
+        dict.set_item("pitch", shape.pitch)?;
+        dict.set_item("hot_spot_x", shape.hot_spot_x)?;
+        dict.set_item("hot_spot_y", shape.hot_spot_y)?;
+        dict.set_item("mask", shape.mask.clone())?;
+        Ok(Some(dict))
+    }
+
+    /// Regions that moved within the desktop since the last frame, as `(source_x, source_y,
+    /// width, height)` clipped to the requested capture area. Empty when the frame carried no
+    /// metadata (only the pointer moved, or nothing changed at all).
+    pub fn move_rects(&self) -> Vec<FrameRect> {
+        self.move_rects.clone()
+    }
+
+    /// Regions whose pixels changed since the last frame, as `(x, y, width, height)` clipped to
+    /// the requested capture area.
+    pub fn dirty_rects(&self) -> Vec<FrameRect> {
+        self.dirty_rects.clone()
+    }
+
+    /// QPC timestamp of the last present that contributed to this frame, as raw ticks.
+    #[getter]
+    pub fn last_present_time(&self) -> i64 {
+        self.last_present_time
+    }
+
+    /// `last_present_time` converted to seconds via `QueryPerformanceFrequency`, suitable for
+    /// diffing against `time.perf_counter()`-style clocks on the Python side.
+    pub fn last_present_time_seconds(&self) -> f64 {
+        qpc_ticks_to_seconds(self.last_present_time)
+    }
+
+    /// QPC timestamp of the last mouse update that contributed to this frame, as raw ticks.
+    #[getter]
+    pub fn last_mouse_update_time(&self) -> i64 {
+        self.last_mouse_update_time
+    }
+
+    /// `last_mouse_update_time` converted to seconds via `QueryPerformanceFrequency`.
+    pub fn last_mouse_update_time_seconds(&self) -> f64 {
+        qpc_ticks_to_seconds(self.last_mouse_update_time)
+    }
+
+    /// Number of desktop frames accumulated into this one; `0` means only the pointer moved.
+    #[getter]
+    pub fn accumulated_frames(&self) -> u32 {
+        self.accumulated_frames
+    }
+
+    /// Whether the desktop image was masked out due to protected content.
+    #[getter]
+    pub fn protected_content(&self) -> bool {
+        self.protected_content
+    }
+
+    /// Cheaply detects the "pointer-only update" case: no desktop pixels changed and only the
+    /// cursor moved, so callers can skip re-encoding an unchanged screen.
+    pub fn is_pointer_only_update(&self) -> bool {
+        self.accumulated_frames == 0 && self.dirty_rects.is_empty() && self.move_rects.is_empty()
+    }
+
     pub fn buffer_ptr(&self) -> usize {
         self.ptr as usize
     }
@@ -311,3 +1019,318 @@ impl NativeDxgiDuplicationFrame {
         }
     }
 }
+
+/// Union of every monitor's virtual-desktop rect, or `None` if the list is empty.
+fn bounding_rect(rects: &[RECT]) -> Option<RECT> {
+    let mut iter = rects.iter();
+    let first = *iter.next()?;
+
+    Some(iter.fold(first, |acc, r| RECT {
+        left: acc.left.min(r.left),
+        top: acc.top.min(r.top),
+        right: acc.right.max(r.right),
+        bottom: acc.bottom.max(r.bottom),
+    }))
+}
+
+/// A single monitor's duplication session as tracked by `NativeDxgiDuplicationDesktop`.
+struct MonitorSession {
+    monitor: Monitor,
+    duplication: DxgiDuplicationApi,
+    /// This monitor's bounds in virtual-desktop coordinates.
+    rect: RECT,
+    /// The last successfully copied rows for this monitor (tightly packed, `bytes_per_pixel`
+    /// wide), reused when a single monitor times out so it doesn't stall the combined frame.
+    last_good: Option<Vec<u8>>,
+    recreate_attempts: u32,
+    recreate_delay_ms: u64,
+}
+
+/// Combines every monitor's duplication output into a single virtual-desktop-sized frame.
+///
+/// Each `acquire_next_frame` call acquires from every monitor with a shared timeout budget and
+/// blits each monitor's latest staging copy into its virtual-desktop offset, filling any gaps
+/// between non-aligned monitors with zeros. A monitor that times out or loses access falls back
+/// to its last-good frame instead of stalling the whole capture.
+#[pyclass(unsendable)]
+pub struct NativeDxgiDuplicationDesktop {
+    sessions: Vec<MonitorSession>,
+    virtual_rect: RECT,
+    color_format: ColorFormat,
+}
+
+#[pymethods]
+impl NativeDxgiDuplicationDesktop {
+    #[new]
+    #[pyo3(signature = (recreate_attempts=DEFAULT_RECREATE_ATTEMPTS, recreate_delay_ms=DEFAULT_RECREATE_DELAY_MS))]
+    pub fn new(recreate_attempts: u32, recreate_delay_ms: u64) -> PyResult<Self> {
+        let monitors =
+            Monitor::enumerate().map_err(|e| PyException::new_err(format!("Failed to enumerate monitors: {e}")))?;
+
+        if monitors.is_empty() {
+            return Err(PyException::new_err("No monitors available for capture"));
+        }
+
+        let mut sessions = Vec::with_capacity(monitors.len());
+        let mut rects = Vec::with_capacity(monitors.len());
+
+        for monitor in monitors {
+            let rect =
+                monitor.rect().map_err(|e| PyException::new_err(format!("Failed to read monitor geometry: {e}")))?;
+            let (_, duplication) =
+                NativeDxgiDuplication::new_duplication(monitor, recreate_attempts, recreate_delay_ms).map_err(
+                    |e| PyException::new_err(format!("Failed to create DXGI duplication session: {e}")),
+                )?;
+
+            rects.push(rect);
+            sessions.push(MonitorSession {
+                monitor,
+                duplication,
+                rect,
+                last_good: None,
+                recreate_attempts,
+                recreate_delay_ms,
+            });
+        }
+
+        let virtual_rect = bounding_rect(&rects).expect("sessions is non-empty");
+
+        Ok(Self { sessions, virtual_rect, color_format: ColorFormat::Bgra8 })
+    }
+
+    /// Returns each monitor's sub-rectangle within the unified buffer, as `(x, y, width, height)`,
+    /// in the same order frames are composited (and thus usable to map back from it).
+    pub fn sub_rectangles(&self) -> Vec<FrameRect> {
+        self.sessions
+            .iter()
+            .map(|s| {
+                (
+                    s.rect.left - self.virtual_rect.left,
+                    s.rect.top - self.virtual_rect.top,
+                    (s.rect.right - s.rect.left) as u32,
+                    (s.rect.bottom - s.rect.top) as u32,
+                )
+            })
+            .collect()
+    }
+
+    #[pyo3(signature = (timeout_ms=16))]
+    pub fn acquire_next_frame(&mut self, timeout_ms: u32) -> PyResult<NativeDxgiDuplicationDesktopFrame> {
+        let bytes_per_pixel = NativeDxgiDuplication::bytes_per_pixel(self.color_format);
+        let width = (self.virtual_rect.right - self.virtual_rect.left) as u32;
+        let height = (self.virtual_rect.bottom - self.virtual_rect.top) as u32;
+        let row_pitch = width as usize * bytes_per_pixel;
+
+        let mut buffer = vec![0u8; row_pitch * height as usize];
+        let deadline = std::time::Instant::now() + Duration::from_millis(u64::from(timeout_ms));
+
+        for session in &mut self.sessions {
+            let remaining_ms = deadline
+                .saturating_duration_since(std::time::Instant::now())
+                .as_millis()
+                .try_into()
+                .unwrap_or(u32::MAX)
+                .max(1);
+
+            let offset_x = (session.rect.left - self.virtual_rect.left) as usize;
+            let offset_y = (session.rect.top - self.virtual_rect.top) as usize;
+            let monitor_width = (session.rect.right - session.rect.left) as usize;
+            let monitor_height = (session.rect.bottom - session.rect.top) as usize;
+
+            let copied = match session.duplication.acquire_next_frame(remaining_ms) {
+                Ok(frame) => {
+                    let texture_desc = *frame.texture_desc();
+                    let monitor_color_format = NativeDxgiDuplication::color_format_from_dxgi(texture_desc.Format)?;
+                    let monitor_bytes_per_pixel = NativeDxgiDuplication::bytes_per_pixel(monitor_color_format);
+
+                    // The duplication texture's pixel dimensions don't necessarily match the
+                    // monitor's virtual-desktop rect (e.g. per-monitor DPI scaling), so clamp the
+                    // copied extent to whichever is smaller to avoid reading past the mapped buffer.
+                    let copy_width = monitor_width.min(texture_desc.Width as usize);
+                    let copy_height = monitor_height.min(texture_desc.Height as usize);
+
+                    let src_box = D3D11_BOX {
+                        left: 0,
+                        top: 0,
+                        front: 0,
+                        right: texture_desc.Width,
+                        bottom: texture_desc.Height,
+                        back: 1,
+                    };
+                    let staging_desc = D3D11_TEXTURE2D_DESC {
+                        Width: texture_desc.Width,
+                        Height: texture_desc.Height,
+                        MipLevels: 1,
+                        ArraySize: 1,
+                        Format: texture_desc.Format,
+                        SampleDesc: DXGI_SAMPLE_DESC { Count: 1, Quality: 0 },
+                        Usage: D3D11_USAGE_STAGING,
+                        BindFlags: 0,
+                        CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+                        MiscFlags: 0,
+                    };
+
+                    let device_context = frame.device_context();
+                    let device = frame.device();
+
+                    let mut staging = None;
+                    unsafe { device.CreateTexture2D(&staging_desc, None, Some(&mut staging)) }
+                        .map_err(|e| PyException::new_err(format!("Failed to create staging texture: {e}")))?;
+                    let staging = staging.expect("CreateTexture2D returned Ok but no texture");
+
+                    unsafe {
+                        device_context.CopySubresourceRegion(
+                            &staging,
+                            0,
+                            0,
+                            0,
+                            0,
+                            frame.texture(),
+                            0,
+                            Some(&src_box as *const _),
+                        );
+                    }
+
+                    let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+                    unsafe { device_context.Map(&staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped)) }
+                        .map_err(|e| PyException::new_err(format!("Failed to map duplication frame: {e}")))?;
+
+                    let src_row_pitch = mapped.RowPitch as usize;
+                    let dst_row_pitch = monitor_width * bytes_per_pixel;
+                    let mut rows = vec![0u8; dst_row_pitch * monitor_height];
+
+                    if matches!(monitor_color_format, ColorFormat::Bgra8) {
+                        let copy_bytes = copy_width * bytes_per_pixel;
+                        for row in 0..copy_height {
+                            unsafe {
+                                let src = mapped.pData.cast::<u8>().add(row * src_row_pitch);
+                                let dst = rows.as_mut_ptr().add(row * dst_row_pitch);
+                                ptr::copy_nonoverlapping(src, dst, copy_bytes);
+                            }
+                        }
+                    } else {
+                        // Source format differs from the unified (BGRA8) output buffer — e.g. an
+                        // HDR monitor reporting `Rgba16F` — so each pixel is decoded and repacked
+                        // instead of memcpy'd, the same conversion `acquire_next_frame` applies for
+                        // non-native `output_format`s.
+                        for row in 0..copy_height {
+                            for col in 0..copy_width {
+                                unsafe {
+                                    let src = mapped.pData.cast::<u8>().add(row * src_row_pitch + col * monitor_bytes_per_pixel);
+                                    let [r, g, b, a] = read_source_channels(src, monitor_color_format, 1.0);
+                                    let dst = rows.as_mut_ptr().add(row * dst_row_pitch + col * bytes_per_pixel);
+                                    ptr::write(dst, (b * 255.0).round() as u8);
+                                    ptr::write(dst.add(1), (g * 255.0).round() as u8);
+                                    ptr::write(dst.add(2), (r * 255.0).round() as u8);
+                                    ptr::write(dst.add(3), (a * 255.0).round() as u8);
+                                }
+                            }
+                        }
+                    }
+
+                    unsafe {
+                        device_context.Unmap(&staging, 0);
+                    }
+
+                    session.last_good = Some(rows.clone());
+                    Some(rows)
+                }
+                Err(DxgiDuplicationError::AccessLost) => {
+                    let _ = NativeDxgiDuplication::new_duplication(
+                        session.monitor,
+                        session.recreate_attempts,
+                        session.recreate_delay_ms,
+                    )
+                    .map(|(_, duplication)| session.duplication = duplication);
+                    session.last_good.clone()
+                }
+                Err(_) => session.last_good.clone(),
+            };
+
+            if let Some(rows) = copied {
+                let monitor_row_pitch = monitor_width * bytes_per_pixel;
+                for row in 0..monitor_height {
+                    let dst_start = (offset_y + row) * row_pitch + offset_x * bytes_per_pixel;
+                    let src_start = row * monitor_row_pitch;
+                    buffer[dst_start..dst_start + monitor_row_pitch]
+                        .copy_from_slice(&rows[src_start..src_start + monitor_row_pitch]);
+                }
+            }
+        }
+
+        Ok(NativeDxgiDuplicationDesktopFrame {
+            buffer,
+            width,
+            height,
+            bytes_per_pixel,
+            row_pitch,
+            color_format: NativeDxgiDuplication::color_format_to_str(self.color_format),
+        })
+    }
+}
+
+/// A composited multi-monitor frame returned by `NativeDxgiDuplicationDesktop::acquire_next_frame`.
+///
+/// Keeps the same `width`/`height`/`bytes_per_row`/`buffer_view` contract as
+/// `NativeDxgiDuplicationFrame`, but owns its pixel data instead of holding a live GPU mapping.
+#[pyclass(unsendable)]
+pub struct NativeDxgiDuplicationDesktopFrame {
+    buffer: Vec<u8>,
+    width: u32,
+    height: u32,
+    bytes_per_pixel: usize,
+    row_pitch: usize,
+    color_format: &'static str,
+}
+
+#[pymethods]
+impl NativeDxgiDuplicationDesktopFrame {
+    #[getter]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[getter]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    #[getter]
+    pub fn bytes_per_pixel(&self) -> usize {
+        self.bytes_per_pixel
+    }
+
+    #[getter]
+    pub fn color_format(&self) -> &'static str {
+        self.color_format
+    }
+
+    #[getter]
+    pub fn bytes_per_row(&self) -> usize {
+        self.row_pitch
+    }
+
+    pub fn buffer_ptr(&self) -> usize {
+        self.buffer.as_ptr() as usize
+    }
+
+    pub fn buffer_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.buffer.clone()
+    }
+
+    pub fn buffer_view<'py>(&'py self, py: Python<'py>) -> PyResult<Bound<'py, PyMemoryView>> {
+        let len = isize::try_from(self.buffer.len()).map_err(|_| PyException::new_err("Frame too large for memoryview"))?;
+        const PYBUF_READ: c_int = 0x100;
+        let view = unsafe { ffi::PyMemoryView_FromMemory(self.buffer.as_ptr().cast_mut().cast::<c_char>(), len, PYBUF_READ) };
+        if view.is_null() {
+            Err(PyException::new_err("Failed to create memoryview for DXGI frame"))
+        } else {
+            let any = unsafe { Bound::from_owned_ptr(py, view) };
+            any.downcast_into().map_err(|e| e.into())
+        }
+    }
+}